@@ -0,0 +1,55 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+	Io(io::Error),
+	Json(serde_json::Error),
+	UrlParse(url::ParseError),
+	UnsupportedHttp,
+	InvalidFormat,
+	ExpectedBody,
+	#[cfg(feature = "tls")]
+	Tls(rustls::Error),
+	TlsNotSupported,
+	TooManyRedirects,
+	Timeout,
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Io(err) => write!(f, "io error: {err}"),
+			Self::Json(err) => write!(f, "json error: {err}"),
+			Self::UrlParse(err) => write!(f, "url parse error: {err}"),
+			Self::UnsupportedHttp => write!(f, "unsupported http version"),
+			Self::InvalidFormat => write!(f, "invalid response format"),
+			Self::ExpectedBody => write!(f, "expected a response body"),
+			#[cfg(feature = "tls")]
+			Self::Tls(err) => write!(f, "tls error: {err}"),
+			Self::TlsNotSupported => write!(f, "https url given but the `tls` feature is disabled"),
+			Self::TooManyRedirects => write!(f, "exceeded the maximum number of redirects"),
+			Self::Timeout => write!(f, "timed out"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+	fn from(err: io::Error) -> Self {
+		Self::Io(err)
+	}
+}
+
+impl From<serde_json::Error> for Error {
+	fn from(err: serde_json::Error) -> Self {
+		Self::Json(err)
+	}
+}
+
+impl From<url::ParseError> for Error {
+	fn from(err: url::ParseError) -> Self {
+		Self::UrlParse(err)
+	}
+}
@@ -0,0 +1,59 @@
+use std::io::Read;
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+
+use crate::Error;
+
+/// Inflates `body` according to a `Content-Encoding` value. Returns `None`
+/// for an encoding this crate doesn't recognize, leaving `body` untouched,
+/// so callers don't mistake a still-compressed body for a plain one.
+pub(crate) fn decode(encoding: &str, body: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+	let mut decoded = Vec::new();
+
+	match encoding {
+		"gzip" | "x-gzip" => {
+			GzDecoder::new(body).read_to_end(&mut decoded)?;
+		}
+		"deflate" => {
+			ZlibDecoder::new(body).read_to_end(&mut decoded)?;
+		}
+		_ => return Ok(None),
+	}
+
+	Ok(Some(decoded))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::Write;
+
+	use flate2::write::{GzEncoder, ZlibEncoder};
+	use flate2::Compression;
+
+	use super::*;
+
+	#[test]
+	fn decode_gzip_round_trips() {
+		let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+		encoder.write_all(b"hello world").unwrap();
+		let compressed = encoder.finish().unwrap();
+
+		assert_eq!(decode("gzip", &compressed).unwrap(), Some(b"hello world".to_vec()));
+		assert_eq!(decode("x-gzip", &compressed).unwrap(), Some(b"hello world".to_vec()));
+	}
+
+	#[test]
+	fn decode_deflate_round_trips() {
+		let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+		encoder.write_all(b"hello world").unwrap();
+		let compressed = encoder.finish().unwrap();
+
+		assert_eq!(decode("deflate", &compressed).unwrap(), Some(b"hello world".to_vec()));
+	}
+
+	#[test]
+	fn decode_passes_through_unrecognized_encoding() {
+		assert_eq!(decode("br", b"still compressed").unwrap(), None);
+		assert_eq!(decode("zstd", b"still compressed").unwrap(), None);
+	}
+}
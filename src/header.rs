@@ -0,0 +1,5 @@
+#[derive(Debug, Clone)]
+pub struct Header {
+	pub name: String,
+	pub value: String,
+}
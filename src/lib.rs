@@ -0,0 +1,14 @@
+mod error;
+#[cfg(feature = "gzip")]
+mod gzip;
+mod header;
+pub mod request;
+pub mod response;
+mod stream;
+#[cfg(feature = "tls")]
+mod tls;
+
+pub use error::Error;
+pub use header::Header;
+pub use request::{Method, Request, RequestBuilder};
+pub use response::Response;
@@ -1,16 +1,27 @@
 use std::io::{self, Read, Write};
 use std::net::TcpStream;
+use std::time::{Duration, Instant};
 
 use serde::Serialize;
 use url::{ParseError, Url};
 
 use super::header::Header;
 use super::response::Response;
+use crate::stream::Stream;
+use crate::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Method {
 	Get,
 	Post,
+	Put,
+	Patch,
+	Delete,
+	Head,
+	Options,
+	Connect,
+	Trace,
+	Custom(String),
 }
 
 impl Method {
@@ -18,6 +29,14 @@ impl Method {
 		match self {
 			Self::Get => b"GET",
 			Self::Post => b"POST",
+			Self::Put => b"PUT",
+			Self::Patch => b"PATCH",
+			Self::Delete => b"DELETE",
+			Self::Head => b"HEAD",
+			Self::Options => b"OPTIONS",
+			Self::Connect => b"CONNECT",
+			Self::Trace => b"TRACE",
+			Self::Custom(method) => method.as_bytes(),
 		}
 	}
 }
@@ -28,10 +47,13 @@ pub struct Request {
 	method: Method,
 	body: Option<Vec<u8>>,
 	headers: Vec<Header>,
+	max_redirects: usize,
+	deadline: Option<Instant>,
 }
 
 impl Request {
 	pub const BUF_SIZE: usize = 1024;
+	pub const DEFAULT_MAX_REDIRECTS: usize = 10;
 
 	pub fn get<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
 		RequestBuilder::new(Method::Get, url)
@@ -41,55 +63,371 @@ impl Request {
 		RequestBuilder::new(Method::Post, url)
 	}
 
-	pub fn send(self) -> Result<Response, io::Error> {
-		let mut stream = TcpStream::connect(self.url.socket_addrs(|| None)?.as_slice())?;
+	pub fn put<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
+		RequestBuilder::new(Method::Put, url)
+	}
+
+	pub fn patch<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
+		RequestBuilder::new(Method::Patch, url)
+	}
+
+	pub fn delete<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
+		RequestBuilder::new(Method::Delete, url)
+	}
+
+	pub fn head<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
+		RequestBuilder::new(Method::Head, url)
+	}
+
+	pub fn options<U: TryInto<Url, Error = ParseError>>(url: U) -> RequestBuilder {
+		RequestBuilder::new(Method::Options, url)
+	}
+
+	pub fn send(mut self) -> Result<Response, Error> {
+		for hop in 0.. {
+			let response = self.execute()?;
+
+			let Some(location) = redirect_location(&response) else {
+				return Ok(response);
+			};
+
+			if hop >= self.max_redirects {
+				return Err(Error::TooManyRedirects);
+			}
+
+			self.follow_redirect(response.status(), location)?;
+		}
+
+		unreachable!()
+	}
+
+	/// Points this request at a redirect target, adjusting the method and
+	/// body per the status code's semantics: 303 downgrades everything but
+	/// HEAD to a bodyless GET, 301/302 only downgrade a POST (matching what
+	/// curl/reqwest do), and 307/308 always preserve the method and body.
+	/// Also drops credential-bearing headers if the redirect crosses origins.
+	fn follow_redirect(&mut self, status: u16, location: &str) -> Result<(), Error> {
+		let next_url = self.url.join(location)?;
+
+		if (status == 303 && self.method != Method::Head) || ((status == 301 || status == 302) && self.method == Method::Post) {
+			self.method = Method::Get;
+			self.body = None;
+			self.headers
+				.retain(|header| !header.name.eq_ignore_ascii_case("content-length") && !header.name.eq_ignore_ascii_case("content-type"));
+		}
+
+		if is_cross_origin(&self.url, &next_url) {
+			self.headers.retain(|header| {
+				!matches!(
+					header.name.to_ascii_lowercase().as_str(),
+					"authorization" | "cookie" | "proxy-authorization"
+				)
+			});
+		}
+
+		self.url = next_url;
+		self.set_host_header();
+
+		Ok(())
+	}
+
+	fn set_host_header(&mut self) {
+		self.headers.retain(|header| !header.name.eq_ignore_ascii_case("host"));
+
+		if let Some(host) = self.url.host_str() {
+			self.headers.push(Header {
+				name: "host".into(),
+				value: host.into(),
+			});
+		}
+	}
+
+	fn execute(&self) -> Result<Response, Error> {
+		let mut stream = self.connect()?;
 
+		stream.set_write_timeout(self.remaining()?)?;
 		self.write(&mut stream)?;
 		stream.flush()?;
 
-		let mut sink = Vec::new();
 		let mut buf = [0u8; Self::BUF_SIZE];
+		let mut sink = Vec::new();
+
+		let (mut response, consumed) = loop {
+			if let Some(parsed) = Response::parse(&sink)? {
+				break parsed;
+			}
 
-		loop {
-			let n = stream.read(&mut buf)?;
+			let n = self.read(&mut stream, &mut buf)?;
+
+			if n == 0 {
+				return Err(Error::InvalidFormat);
+			}
 
 			sink.extend_from_slice(&buf[..n]);
+		};
+
+		let mut body = sink.split_off(consumed);
+
+		// HEAD responses carry headers describing a body (e.g. content-length)
+		// that the server never actually sends.
+		if self.method == Method::Head {
+			return Ok(response);
+		}
+
+		if let Some(len) = response
+			.header("content-length")
+			.and_then(|value| value.trim().parse::<usize>().ok())
+		{
+			while body.len() < len {
+				let n = self.read(&mut stream, &mut buf)?;
 
-			if n < Self::BUF_SIZE {
-				break;
+				if n == 0 {
+					break;
+				}
+
+				body.extend_from_slice(&buf[..n]);
+			}
+
+			body.truncate(len);
+		} else if response
+			.header("transfer-encoding")
+			.is_some_and(|value| value.eq_ignore_ascii_case("chunked"))
+		{
+			body = read_chunked_body(self, &mut stream, body)?;
+		} else {
+			loop {
+				let n = self.read(&mut stream, &mut buf)?;
+
+				if n == 0 {
+					break;
+				}
+
+				body.extend_from_slice(&buf[..n]);
 			}
 		}
 
-		Response::from_bytes(sink)
+		#[cfg(feature = "gzip")]
+		if let Some(encoding) = response.header("content-encoding").map(|value| value.trim().to_ascii_lowercase()) {
+			if let Some(decoded) = crate::gzip::decode(&encoding, &body)? {
+				body = decoded;
+				response.remove_header("content-encoding");
+				response.set_header("content-length", body.len().to_string());
+			}
+		}
+
+		response.body = if body.is_empty() { None } else { Some(body) };
+
+		Ok(response)
+	}
+
+	fn connect(&self) -> Result<Stream, Error> {
+		let addrs = self.url.socket_addrs(|| None)?;
+
+		let tcp = match self.remaining()? {
+			Some(_) => {
+				let mut last_err = None;
+
+				addrs
+					.iter()
+					.find_map(|addr| match TcpStream::connect_timeout(addr, self.remaining().ok().flatten()?) {
+						Ok(stream) => Some(stream),
+						Err(err) => {
+							last_err = Some(err);
+							None
+						}
+					})
+					.ok_or_else(|| match last_err {
+						Some(err) if err.kind() != io::ErrorKind::TimedOut => Error::from(err),
+						Some(_) => Error::Timeout,
+						None => self.remaining().err().unwrap_or(Error::InvalidFormat),
+					})?
+			}
+			None => TcpStream::connect(addrs.as_slice())?,
+		};
+
+		match self.url.scheme() {
+			"https" => {
+				#[cfg(feature = "tls")]
+				{
+					let host = self.url.host_str().ok_or(Error::InvalidFormat)?;
+					Ok(Stream::Tls(Box::new(crate::tls::connect(tcp, host)?)))
+				}
+
+				#[cfg(not(feature = "tls"))]
+				{
+					Err(Error::TlsNotSupported)
+				}
+			}
+			_ => Ok(Stream::Plain(tcp)),
+		}
 	}
 
-	fn write(&self, stream: &mut TcpStream) -> io::Result<()> {
-		stream.write_all(self.method.as_bytes())?;
-		stream.write_all(b" ")?;
-		stream.write_all(self.url.path().as_bytes())?;
+	/// Returns the time left before `deadline`, or `None` if no deadline was
+	/// set. Returns `Error::Timeout` if the deadline has already passed.
+	fn remaining(&self) -> Result<Option<Duration>, Error> {
+		let Some(deadline) = self.deadline else {
+			return Ok(None);
+		};
+
+		deadline
+			.checked_duration_since(Instant::now())
+			.filter(|remaining| !remaining.is_zero())
+			.map(Some)
+			.ok_or(Error::Timeout)
+	}
+
+	/// Reads from `stream`, applying (and recomputing, each call) the
+	/// request's read deadline so a stalled server can't block forever.
+	fn read(&self, stream: &mut Stream, buf: &mut [u8]) -> Result<usize, Error> {
+		let remaining = self.remaining()?;
+		stream.set_read_timeout(remaining)?;
+
+		match stream.read(buf) {
+			Ok(n) => Ok(n),
+			Err(err)
+				if self.deadline.is_some()
+					&& matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) =>
+			{
+				Err(Error::Timeout)
+			}
+			Err(err) => Err(err.into()),
+		}
+	}
+
+	/// Writes the request line, headers and body to `stream`, applying the
+	/// same timeout-kind-to-`Error::Timeout` mapping `read` uses so a stalled
+	/// peer surfaces as `Error::Timeout` instead of a generic `Error::Io`.
+	fn write(&self, stream: &mut Stream) -> Result<(), Error> {
+		let mut buf = Vec::new();
+
+		buf.extend_from_slice(self.method.as_bytes());
+		buf.extend_from_slice(b" ");
+		buf.extend_from_slice(self.url.path().as_bytes());
 
 		if let Some(query) = self.url.query() {
-			stream.write_all(query.as_bytes())?;
+			buf.extend_from_slice(query.as_bytes());
 		}
 
-		stream.write_all(b" HTTP/1.1\r\n")?;
+		buf.extend_from_slice(b" HTTP/1.1\r\n");
 
 		for header in &self.headers {
-			stream.write_all(header.name.as_bytes())?;
-			stream.write_all(b": ")?;
-			stream.write_all(header.value.as_bytes())?;
-			stream.write_all(b"\r\n")?;
+			buf.extend_from_slice(header.name.as_bytes());
+			buf.extend_from_slice(b": ");
+			buf.extend_from_slice(header.value.as_bytes());
+			buf.extend_from_slice(b"\r\n");
 		}
 
 		if let Some(body) = &self.body {
-			stream.write_all(b"\r\n")?;
-			stream.write_all(body.as_slice())?;
+			buf.extend_from_slice(b"\r\n");
+			buf.extend_from_slice(body.as_slice());
 		}
 
-		Ok(())
+		match stream.write_all(&buf) {
+			Ok(()) => Ok(()),
+			Err(err)
+				if self.deadline.is_some()
+					&& matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) =>
+			{
+				Err(Error::Timeout)
+			}
+			Err(err) => Err(err.into()),
+		}
 	}
 }
 
+/// Returns the `Location` header of `response` if its status is a redirect
+/// (301, 302, 303, 307 or 308).
+fn redirect_location(response: &Response) -> Option<&str> {
+	if !matches!(response.status(), 301 | 302 | 303 | 307 | 308) {
+		return None;
+	}
+
+	response.header("location")
+}
+
+/// Whether `next` points at a different scheme, host, or port than `current`,
+/// and so shouldn't be trusted with `current`'s credentials.
+fn is_cross_origin(current: &Url, next: &Url) -> bool {
+	current.scheme() != next.scheme()
+		|| current.host_str() != next.host_str()
+		|| current.port_or_known_default() != next.port_or_known_default()
+}
+
+/// Decodes a `Transfer-Encoding: chunked` body from `stream`, using
+/// `leftover` as any body bytes already pulled off the wire while reading
+/// the response headers.
+fn read_chunked_body(request: &Request, stream: &mut Stream, mut leftover: Vec<u8>) -> Result<Vec<u8>, Error> {
+	let mut body = Vec::new();
+	let mut buf = [0u8; Request::BUF_SIZE];
+
+	loop {
+		let line_end = loop {
+			if let Some(pos) = find_crlf(&leftover) {
+				break pos;
+			}
+
+			let n = request.read(stream, &mut buf)?;
+
+			if n == 0 {
+				return Err(Error::InvalidFormat);
+			}
+
+			leftover.extend_from_slice(&buf[..n]);
+		};
+
+		let size_line = core::str::from_utf8(&leftover[..line_end]).map_err(|_| Error::InvalidFormat)?;
+		let size_str = size_line.split(';').next().unwrap_or("").trim();
+		let size = usize::from_str_radix(size_str, 16).map_err(|_| Error::InvalidFormat)?;
+
+		leftover.drain(0..line_end + 2);
+
+		if size == 0 {
+			// consume trailer headers up to the final blank line
+			loop {
+				match find_crlf(&leftover) {
+					Some(0) => {
+						leftover.drain(0..2);
+						break;
+					}
+					Some(pos) => {
+						leftover.drain(0..pos + 2);
+					}
+					None => {
+						let n = request.read(stream, &mut buf)?;
+
+						if n == 0 {
+							break;
+						}
+
+						leftover.extend_from_slice(&buf[..n]);
+					}
+				};
+			}
+
+			break;
+		}
+
+		while leftover.len() < size + 2 {
+			let n = request.read(stream, &mut buf)?;
+
+			if n == 0 {
+				return Err(Error::InvalidFormat);
+			}
+
+			leftover.extend_from_slice(&buf[..n]);
+		}
+
+		body.extend_from_slice(&leftover[..size]);
+		leftover.drain(0..size + 2);
+	}
+
+	Ok(body)
+}
+
+fn find_crlf(bytes: &[u8]) -> Option<usize> {
+	crate::response::find(bytes, b"\r\n")
+}
+
 pub struct RequestBuilder {
 	request: Request,
 }
@@ -98,27 +436,58 @@ impl RequestBuilder {
 	pub fn new<U: TryInto<Url, Error = ParseError>>(method: Method, url: U) -> Self {
 		let url = url.try_into().unwrap();
 
+		#[cfg_attr(not(feature = "gzip"), allow(unused_mut))]
+		let mut headers = if let Some(host) = url.host_str() {
+			vec![Header {
+				name: "host".into(),
+				value: host.into(),
+			}]
+		} else {
+			vec![]
+		};
+
+		#[cfg(feature = "gzip")]
+		headers.push(Header {
+			name: "accept-encoding".into(),
+			value: "gzip, deflate".into(),
+		});
+
 		Self {
 			request: Request {
 				method,
 				body: None,
-				headers: if let Some(host) = url.host_str() {
-					vec![Header {
-						name: "host".into(),
-						value: host.into(),
-					}]
-				} else {
-					vec![]
-				},
+				headers,
 				url,
+				max_redirects: Request::DEFAULT_MAX_REDIRECTS,
+				deadline: None,
 			},
 		}
 	}
 
-	pub fn send(self) -> Result<Response, io::Error> {
+	pub fn send(self) -> Result<Response, Error> {
 		self.request.send()
 	}
 
+	/// Sets how many redirects this request will follow before giving up
+	/// with `Error::TooManyRedirects`. Defaults to 10.
+	pub fn max_redirects(mut self, max_redirects: usize) -> Self {
+		self.request.max_redirects = max_redirects;
+		self
+	}
+
+	/// Bounds the total time this request may spend connecting and reading
+	/// the response. Exceeding it surfaces as `Error::Timeout`.
+	pub fn timeout(self, timeout: Duration) -> Self {
+		self.deadline(Instant::now() + timeout)
+	}
+
+	/// Like [`RequestBuilder::timeout`], but takes an absolute deadline
+	/// instead of a duration from now.
+	pub fn deadline(mut self, deadline: Instant) -> Self {
+		self.request.deadline = Some(deadline);
+		self
+	}
+
 	pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
 		self.request.headers.push(Header {
 			name: name.into(),
@@ -127,13 +496,239 @@ impl RequestBuilder {
 		self
 	}
 
-	pub fn json<T: Serialize + ?Sized>(mut self, payload: &T) -> Self {
-		// FIXME: handle errors
-		let bytes = serde_json::to_vec(payload).expect("invalid JSON body");
+	/// Sets the request body to `body`, adding a matching `content-length`
+	/// header. Use [`RequestBuilder::json`] or [`RequestBuilder::form`] if
+	/// you also want the `content-type` set for you.
+	pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+		let bytes = body.into();
 		let len = bytes.len();
 
 		self.request.body = Some(bytes);
 		self.header("content-length", format!("{len}"))
-			.header("content-type", "application/json")
+	}
+
+	pub fn json<T: Serialize + ?Sized>(self, payload: &T) -> Self {
+		// FIXME: handle errors
+		let bytes = serde_json::to_vec(payload).expect("invalid JSON body");
+
+		self.body(bytes).header("content-type", "application/json")
+	}
+
+	/// Url-encodes `payload` as the request body, e.g. for HTML form
+	/// submissions.
+	pub fn form<T: Serialize + ?Sized>(self, payload: &T) -> Self {
+		// FIXME: handle errors
+		let body = serde_urlencoded::to_string(payload).expect("invalid form body");
+
+		self.body(body.into_bytes())
+			.header("content-type", "application/x-www-form-urlencoded")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::TcpListener;
+	use std::thread;
+	use std::time::Duration;
+
+	use super::*;
+
+	#[test]
+	fn write_timeout_surfaces_as_error_timeout() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+
+		// Accept the connection but never read from it, so the client's
+		// write eventually blocks on a full send buffer.
+		let handle = thread::spawn(move || {
+			let (stream, _) = listener.accept().unwrap();
+			thread::sleep(Duration::from_secs(2));
+			drop(stream);
+		});
+
+		let body = vec![0u8; 32 * 1024 * 1024];
+		let result = Request::post(format!("http://{addr}/").as_str())
+			.timeout(Duration::from_millis(50))
+			.body(body)
+			.send();
+
+		assert!(matches!(result, Err(Error::Timeout)));
+
+		handle.join().unwrap();
+	}
+
+	#[test]
+	fn chunked_response_body_reassembles_across_partial_reads() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+
+		let response =
+			b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\nX-Trailer: ignored\r\n\r\n";
+
+		let handle = thread::spawn(move || {
+			let (mut stream, _) = listener.accept().unwrap();
+			let mut discard = [0u8; 1024];
+			let _ = stream.read(&mut discard);
+
+			// Dribble the response out a few bytes at a time so the client
+			// has to stitch chunks together across multiple reads.
+			for piece in response.chunks(7) {
+				stream.write_all(piece).unwrap();
+				stream.flush().unwrap();
+				thread::sleep(Duration::from_millis(5));
+			}
+		});
+
+		let response = Request::get(format!("http://{addr}/").as_str()).send().unwrap();
+
+		assert_eq!(response.body.as_deref(), Some(b"hello world".as_slice()));
+
+		handle.join().unwrap();
+	}
+
+	#[test]
+	fn chunked_response_rejects_malformed_chunk_size() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+
+		let response = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nnotsize\r\nhello\r\n0\r\n\r\n";
+
+		let handle = thread::spawn(move || {
+			let (mut stream, _) = listener.accept().unwrap();
+			let mut discard = [0u8; 1024];
+			let _ = stream.read(&mut discard);
+			stream.write_all(response).unwrap();
+		});
+
+		let result = Request::get(format!("http://{addr}/").as_str()).send();
+
+		assert!(matches!(result, Err(Error::InvalidFormat)));
+
+		handle.join().unwrap();
+	}
+
+	fn response_with_status(status: u16, location: Option<&str>) -> Response {
+		let mut raw = format!("HTTP/1.1 {status} OK\r\n");
+
+		if let Some(location) = location {
+			raw.push_str(&format!("Location: {location}\r\n"));
+		}
+
+		raw.push_str("\r\n");
+
+		Response::parse(raw.as_bytes()).unwrap().unwrap().0
+	}
+
+	fn make_request(method: Method, url: &str, headers: Vec<Header>, body: Option<Vec<u8>>) -> Request {
+		Request {
+			url: Url::parse(url).unwrap(),
+			method,
+			body,
+			headers,
+			max_redirects: Request::DEFAULT_MAX_REDIRECTS,
+			deadline: None,
+		}
+	}
+
+	#[test]
+	fn redirect_location_only_fires_on_redirect_statuses() {
+		assert_eq!(redirect_location(&response_with_status(200, Some("/x"))), None);
+		assert_eq!(redirect_location(&response_with_status(404, Some("/x"))), None);
+
+		for status in [301, 302, 303, 307, 308] {
+			assert_eq!(redirect_location(&response_with_status(status, Some("/x"))), Some("/x"));
+		}
+	}
+
+	#[test]
+	fn is_cross_origin_detects_scheme_host_port_changes() {
+		let base = Url::parse("https://example.com/a").unwrap();
+
+		assert!(!is_cross_origin(&base, &Url::parse("https://example.com/b").unwrap()));
+		assert!(is_cross_origin(&base, &Url::parse("http://example.com/a").unwrap()));
+		assert!(is_cross_origin(&base, &Url::parse("https://other.com/a").unwrap()));
+		assert!(is_cross_origin(&base, &Url::parse("https://example.com:8443/a").unwrap()));
+	}
+
+	#[test]
+	fn follow_redirect_downgrades_only_post_on_301_302() {
+		let mut request = make_request(Method::Post, "http://example.com/a", vec![], Some(b"payload".to_vec()));
+		request.follow_redirect(301, "/b").unwrap();
+		assert_eq!(request.method, Method::Get);
+		assert!(request.body.is_none());
+
+		let mut request = make_request(Method::Delete, "http://example.com/a", vec![], None);
+		request.follow_redirect(302, "/b").unwrap();
+		assert_eq!(request.method, Method::Delete);
+	}
+
+	#[test]
+	fn follow_redirect_303_keeps_head_but_downgrades_others() {
+		let mut request = make_request(Method::Head, "http://example.com/a", vec![], None);
+		request.follow_redirect(303, "/b").unwrap();
+		assert_eq!(request.method, Method::Head);
+
+		let mut request = make_request(Method::Put, "http://example.com/a", vec![], Some(b"x".to_vec()));
+		request.follow_redirect(303, "/b").unwrap();
+		assert_eq!(request.method, Method::Get);
+		assert!(request.body.is_none());
+	}
+
+	#[test]
+	fn follow_redirect_307_308_preserve_method_and_body() {
+		for status in [307, 308] {
+			let mut request = make_request(Method::Put, "http://example.com/a", vec![], Some(b"x".to_vec()));
+			request.follow_redirect(status, "/b").unwrap();
+			assert_eq!(request.method, Method::Put);
+			assert_eq!(request.body, Some(b"x".to_vec()));
+		}
+	}
+
+	#[test]
+	fn follow_redirect_strips_credentials_cross_origin() {
+		let mut request = make_request(
+			Method::Get,
+			"http://example.com/a",
+			vec![Header {
+				name: "authorization".into(),
+				value: "secret".into(),
+			}],
+			None,
+		);
+
+		request.follow_redirect(302, "http://other.com/b").unwrap();
+
+		assert!(!request.headers.iter().any(|header| header.name.eq_ignore_ascii_case("authorization")));
+	}
+
+	#[test]
+	fn follow_redirect_keeps_credentials_same_origin() {
+		let mut request = make_request(
+			Method::Get,
+			"http://example.com/a",
+			vec![Header {
+				name: "authorization".into(),
+				value: "secret".into(),
+			}],
+			None,
+		);
+
+		request.follow_redirect(302, "/b").unwrap();
+
+		assert!(request.headers.iter().any(|header| header.name.eq_ignore_ascii_case("authorization")));
+	}
+
+	#[test]
+	fn method_as_bytes_covers_every_verb() {
+		assert_eq!(Method::Get.as_bytes(), b"GET");
+		assert_eq!(Method::Post.as_bytes(), b"POST");
+		assert_eq!(Method::Put.as_bytes(), b"PUT");
+		assert_eq!(Method::Patch.as_bytes(), b"PATCH");
+		assert_eq!(Method::Delete.as_bytes(), b"DELETE");
+		assert_eq!(Method::Head.as_bytes(), b"HEAD");
+		assert_eq!(Method::Options.as_bytes(), b"OPTIONS");
+		assert_eq!(Method::Connect.as_bytes(), b"CONNECT");
+		assert_eq!(Method::Trace.as_bytes(), b"TRACE");
+		assert_eq!(Method::Custom("PROPFIND".into()).as_bytes(), b"PROPFIND");
 	}
 }
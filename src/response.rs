@@ -12,6 +12,31 @@ pub struct Response {
 }
 
 impl Response {
+	pub fn status(&self) -> u16 {
+		self.status
+	}
+
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|header| header.name.eq_ignore_ascii_case(name))
+			.map(|header| header.value.as_str())
+	}
+
+	#[cfg(feature = "gzip")]
+	pub(crate) fn remove_header(&mut self, name: &str) {
+		self.headers.retain(|header| !header.name.eq_ignore_ascii_case(name));
+	}
+
+	#[cfg(feature = "gzip")]
+	pub(crate) fn set_header(&mut self, name: &str, value: String) {
+		self.remove_header(name);
+		self.headers.push(Header {
+			name: name.into(),
+			value,
+		});
+	}
+
 	pub fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
 		let Some(body) = self.body else {
 			return Err(Error::ExpectedBody);
@@ -20,49 +45,53 @@ impl Response {
 		Ok(serde_json::from_slice(&body)?)
 	}
 
-	pub fn from_bytes(mut bytes: Vec<u8>) -> Result<Self, Error> {
-		let mut response = Response {
-			headers: vec![],
-			status: 0,
-			body: None,
+	/// Incrementally parses a status line and header block from `buf`.
+	///
+	/// Returns `Ok(None)` if `buf` doesn't yet contain a full header block
+	/// (a blank line terminating it hasn't arrived), so callers can feed in
+	/// more bytes and try again. On success, returns the parsed response
+	/// (with `body` left unset) alongside the number of bytes of `buf` the
+	/// header block occupied, so the caller can split off anything after it
+	/// as the start of the body. Never panics on malformed or partial input.
+	pub(crate) fn parse(buf: &[u8]) -> Result<Option<(Self, usize)>, Error> {
+		let Some(headers_end) = find(buf, b"\r\n\r\n").map(|i| i + 4) else {
+			return Ok(None);
 		};
 
-		let mut slice = bytes.as_slice();
-
-		slice = extract_http_version(slice)?;
+		let mut slice = expect_http_version(&buf[..headers_end])?;
 		slice = expect_skip(slice, b" ")?;
 
-		let (mut slice, status) = extract_until(slice, b" ");
-		let status: u16 = core::str::from_utf8(status).unwrap().parse().unwrap();
+		let (slice, status) = split_once(slice, b" ").ok_or(Error::InvalidFormat)?;
+		let status: u16 = core::str::from_utf8(status)
+			.map_err(|_| Error::InvalidFormat)?
+			.trim()
+			.parse()
+			.map_err(|_| Error::InvalidFormat)?;
 
-		response.status = status;
+		// skip the rest of the status line (the reason phrase)
+		let (mut slice, _) = split_once(slice, b"\r\n").ok_or(Error::InvalidFormat)?;
 
-		// skip rest of line
-		slice = extract_until(slice, b"\r\n").0;
+		let mut headers = Vec::new();
 
-		// check if headers are next
 		while !slice.starts_with(b"\r\n") {
-			if slice.is_empty() {
-				return Ok(response);
-			}
-
-			let (s, name) = extract_until(slice, b": ");
-			let (s, value) = extract_until(s, b"\r\n");
+			let (rest, line) = split_once(slice, b"\r\n").ok_or(Error::InvalidFormat)?;
+			let (value, name) = split_once(line, b":").ok_or(Error::InvalidFormat)?;
 
-			response.headers.push(Header {
-				name: String::from_utf8_lossy(name).into_owned(),
-				value: String::from_utf8_lossy(value).into_owned(),
+			headers.push(Header {
+				name: String::from_utf8_lossy(name).trim().to_string(),
+				value: String::from_utf8_lossy(value).trim().to_string(),
 			});
 
-			slice = s;
+			slice = rest;
 		}
 
-		slice = expect_skip(slice, b"\r\n")?;
-
-		bytes.drain(0..bytes.len() - slice.len());
+		let response = Response {
+			headers,
+			status,
+			body: None,
+		};
 
-		response.body = Some(bytes);
-		Ok(response)
+		Ok(Some((response, headers_end)))
 	}
 }
 
@@ -74,24 +103,102 @@ fn expect_skip<'a>(bytes: &'a [u8], seq: &[u8]) -> Result<&'a [u8], Error> {
 	Ok(&bytes[seq.len()..])
 }
 
-fn extract_until<'a>(bytes: &'a [u8], seq: &[u8]) -> (&'a [u8], &'a [u8]) {
-	let mut i = 0;
-
-	while !bytes[i..].starts_with(seq) {
-		i += 1;
+/// Finds the first occurrence of `seq` in `bytes`, bounds-checked so it
+/// returns `None` instead of running past the end of the slice.
+pub(crate) fn find(bytes: &[u8], seq: &[u8]) -> Option<usize> {
+	if seq.is_empty() || bytes.len() < seq.len() {
+		return None;
 	}
 
-	let extracted = &bytes[..i];
+	bytes.windows(seq.len()).position(|window| window == seq)
+}
 
-	i += seq.len();
+/// Splits `bytes` at the first occurrence of `seq`, returning `(after, before)`
+/// with `seq` itself consumed. Returns `None` if `seq` doesn't appear.
+fn split_once<'a>(bytes: &'a [u8], seq: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+	let i = find(bytes, seq)?;
 
-	(&bytes[i..], extracted)
+	Some((&bytes[i + seq.len()..], &bytes[..i]))
 }
 
-fn extract_http_version(bytes: &[u8]) -> Result<&[u8], Error> {
-	if !bytes.starts_with(b"HTTP/1.1") {
-		return Err(Error::UnsupportedHttp);
+fn expect_http_version(bytes: &[u8]) -> Result<&[u8], Error> {
+	if bytes.starts_with(b"HTTP/1.0") || bytes.starts_with(b"HTTP/1.1") {
+		Ok(&bytes[b"HTTP/1.1".len()..])
+	} else {
+		Err(Error::UnsupportedHttp)
 	}
+}
 
-	Ok(&bytes[b"HTTP/1.1".len()..])
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_returns_none_on_partial_header_block() {
+		let buf = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n";
+
+		assert!(matches!(Response::parse(buf), Ok(None)));
+	}
+
+	#[test]
+	fn parse_returns_none_with_no_bytes() {
+		assert!(matches!(Response::parse(b""), Ok(None)));
+	}
+
+	#[test]
+	fn parse_reads_status_and_headers() {
+		let buf = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello";
+		let (response, consumed) = Response::parse(buf).unwrap().unwrap();
+
+		assert_eq!(response.status(), 200);
+		assert_eq!(response.header("content-type"), Some("text/plain"));
+		assert_eq!(response.header("content-length"), Some("5"));
+		assert_eq!(&buf[consumed..], b"hello");
+	}
+
+	#[test]
+	fn parse_accepts_http_1_0() {
+		let buf = b"HTTP/1.0 404 Not Found\r\n\r\n";
+		let (response, _) = Response::parse(buf).unwrap().unwrap();
+
+		assert_eq!(response.status(), 404);
+	}
+
+	#[test]
+	fn parse_rejects_unsupported_http_version() {
+		let buf = b"HTTP/2.0 200 OK\r\n\r\n";
+
+		assert!(matches!(Response::parse(buf), Err(Error::UnsupportedHttp)));
+	}
+
+	#[test]
+	fn parse_rejects_malformed_status_code() {
+		let buf = b"HTTP/1.1 notanumber OK\r\n\r\n";
+
+		assert!(matches!(Response::parse(buf), Err(Error::InvalidFormat)));
+	}
+
+	#[test]
+	fn parse_rejects_header_missing_colon() {
+		let buf = b"HTTP/1.1 200 OK\r\nMalformedHeader\r\n\r\n";
+
+		assert!(matches!(Response::parse(buf), Err(Error::InvalidFormat)));
+	}
+
+	#[test]
+	fn parse_trims_header_whitespace() {
+		let buf = b"HTTP/1.1 200 OK\r\nX-Test:   value with spaces  \r\n\r\n";
+		let (response, _) = Response::parse(buf).unwrap().unwrap();
+
+		assert_eq!(response.header("x-test"), Some("value with spaces"));
+	}
+
+	#[test]
+	fn parse_handles_no_headers() {
+		let buf = b"HTTP/1.1 204 No Content\r\n\r\n";
+		let (response, consumed) = Response::parse(buf).unwrap().unwrap();
+
+		assert_eq!(response.status(), 204);
+		assert_eq!(consumed, buf.len());
+	}
 }
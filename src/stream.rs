@@ -0,0 +1,57 @@
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+/// A connection to a server, either plaintext or wrapped in TLS. `Request`
+/// writes and reads through this without needing to know which one it has.
+pub(crate) enum Stream {
+	Plain(TcpStream),
+	#[cfg(feature = "tls")]
+	Tls(Box<rustls::StreamOwned<rustls::ClientConnection, TcpStream>>),
+}
+
+impl Stream {
+	pub(crate) fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+		match self {
+			Self::Plain(stream) => stream.set_read_timeout(timeout),
+			#[cfg(feature = "tls")]
+			Self::Tls(stream) => stream.sock.set_read_timeout(timeout),
+		}
+	}
+
+	pub(crate) fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+		match self {
+			Self::Plain(stream) => stream.set_write_timeout(timeout),
+			#[cfg(feature = "tls")]
+			Self::Tls(stream) => stream.sock.set_write_timeout(timeout),
+		}
+	}
+}
+
+impl Read for Stream {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		match self {
+			Self::Plain(stream) => stream.read(buf),
+			#[cfg(feature = "tls")]
+			Self::Tls(stream) => stream.read(buf),
+		}
+	}
+}
+
+impl Write for Stream {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		match self {
+			Self::Plain(stream) => stream.write(buf),
+			#[cfg(feature = "tls")]
+			Self::Tls(stream) => stream.write(buf),
+		}
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		match self {
+			Self::Plain(stream) => stream.flush(),
+			#[cfg(feature = "tls")]
+			Self::Tls(stream) => stream.flush(),
+		}
+	}
+}
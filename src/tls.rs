@@ -0,0 +1,23 @@
+use std::net::TcpStream;
+use std::sync::Arc;
+
+use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+use rustls_pki_types::ServerName;
+
+use crate::Error;
+
+/// Wraps `tcp` in a rustls client session for `host`, trusting the Mozilla
+/// root set shipped by `webpki-roots`.
+pub(crate) fn connect(tcp: TcpStream, host: &str) -> Result<StreamOwned<ClientConnection, TcpStream>, Error> {
+	let mut roots = RootCertStore::empty();
+	roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+	let config = ClientConfig::builder()
+		.with_root_certificates(roots)
+		.with_no_client_auth();
+
+	let server_name = ServerName::try_from(host.to_owned()).map_err(|_| Error::InvalidFormat)?;
+	let conn = ClientConnection::new(Arc::new(config), server_name).map_err(Error::Tls)?;
+
+	Ok(StreamOwned::new(conn, tcp))
+}